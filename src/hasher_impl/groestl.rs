@@ -0,0 +1,48 @@
+use groestl::Digest as _;
+
+use crate::hasher::{Digest, Hasher};
+
+/// Multihash digest for the Grøstl family.
+#[derive(Clone)]
+pub struct GroestlDigest<const S: usize> {
+  bytes: [u8; S],
+}
+
+impl<const S: usize> Default for GroestlDigest<S> {
+  fn default() -> Self { Self { bytes: [0; S] } }
+}
+
+impl<const S: usize> AsRef<[u8]> for GroestlDigest<S> {
+  fn as_ref(&self) -> &[u8] { &self.bytes }
+}
+
+impl<const S: usize> AsMut<[u8]> for GroestlDigest<S> {
+  fn as_mut(&mut self) -> &mut [u8] { &mut self.bytes }
+}
+
+impl<const S: usize> Digest<S> for GroestlDigest<S> {}
+
+macro_rules! derive_groestl_hasher {
+  ($name:ident, $inner:ty, $size:expr) => {
+    #[derive(Debug, Default)]
+    pub struct $name($inner);
+
+    impl Hasher for $name {
+      type Digest = GroestlDigest<$size>;
+
+      fn update(&mut self, input: &[u8]) { self.0.update(input) }
+
+      fn finalize(&self) -> Self::Digest {
+        let out = self.0.clone().finalize();
+        let mut digest = GroestlDigest::default();
+        digest.bytes.copy_from_slice(&out);
+        digest
+      }
+
+      fn reset(&mut self) { self.0.reset() }
+    }
+  };
+}
+
+derive_groestl_hasher!(Groestl256, groestl::Groestl256, 32);
+derive_groestl_hasher!(Groestl512, groestl::Groestl512, 64);
@@ -0,0 +1,60 @@
+use k12::digest::{ExtendableOutput, Update, XofReader};
+
+use crate::hasher::{Digest, Hasher};
+
+/// KangarooTwelve digest (32-byte default).
+#[derive(Clone)]
+pub struct K12Digest<const S: usize> {
+  bytes: [u8; S],
+}
+
+impl<const S: usize> Default for K12Digest<S> {
+  fn default() -> Self { Self { bytes: [0; S] } }
+}
+
+impl<const S: usize> AsRef<[u8]> for K12Digest<S> {
+  fn as_ref(&self) -> &[u8] { &self.bytes }
+}
+
+impl<const S: usize> AsMut<[u8]> for K12Digest<S> {
+  fn as_mut(&mut self) -> &mut [u8] { &mut self.bytes }
+}
+
+impl<const S: usize> Digest<S> for K12Digest<S> {}
+
+/// KangarooTwelve hasher squeezing a caller-chosen number of bytes (32 by
+/// default), parameterized over a customization string `C` (empty by
+/// default).
+pub struct K12Hasher<const S: usize = 32, C: AsRef<[u8]> + Clone + Default = &'static [u8]>(
+  k12::KangarooTwelve<C>,
+  C,
+);
+
+impl<const S: usize, C: AsRef<[u8]> + Clone + Default> K12Hasher<S, C> {
+  /// Creates a hasher bound to an explicit customization string.
+  pub fn with_customization(customization: C) -> Self {
+    Self(k12::KangarooTwelve::new(customization.clone()), customization)
+  }
+}
+
+impl<const S: usize, C: AsRef<[u8]> + Clone + Default> Default for K12Hasher<S, C> {
+  fn default() -> Self { Self::with_customization(C::default()) }
+}
+
+impl<const S: usize, C: AsRef<[u8]> + Clone + Default> Hasher for K12Hasher<S, C> {
+  type Digest = K12Digest<S>;
+
+  fn update(&mut self, input: &[u8]) { Update::update(&mut self.0, input) }
+
+  fn finalize(&self) -> Self::Digest {
+    let mut reader = self.0.clone().finalize_xof();
+    let mut digest = K12Digest::default();
+    reader.read(&mut digest.bytes);
+    digest
+  }
+
+  fn reset(&mut self) { *self = Self::with_customization(self.1.clone()) }
+}
+
+/// `K12` at the crate's default 32-byte squeeze.
+pub type K12_256 = K12Hasher<32>;
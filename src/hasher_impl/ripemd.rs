@@ -0,0 +1,48 @@
+use ripemd::Digest as _;
+
+use crate::hasher::{Digest, Hasher};
+
+/// Multihash digest for the RIPEMD family.
+#[derive(Clone)]
+pub struct RipemdDigest<const S: usize> {
+  bytes: [u8; S],
+}
+
+impl<const S: usize> Default for RipemdDigest<S> {
+  fn default() -> Self { Self { bytes: [0; S] } }
+}
+
+impl<const S: usize> AsRef<[u8]> for RipemdDigest<S> {
+  fn as_ref(&self) -> &[u8] { &self.bytes }
+}
+
+impl<const S: usize> AsMut<[u8]> for RipemdDigest<S> {
+  fn as_mut(&mut self) -> &mut [u8] { &mut self.bytes }
+}
+
+impl<const S: usize> Digest<S> for RipemdDigest<S> {}
+
+macro_rules! derive_ripemd_hasher {
+  ($name:ident, $inner:ty, $size:expr) => {
+    #[derive(Debug, Default)]
+    pub struct $name($inner);
+
+    impl Hasher for $name {
+      type Digest = RipemdDigest<$size>;
+
+      fn update(&mut self, input: &[u8]) { self.0.update(input) }
+
+      fn finalize(&self) -> Self::Digest {
+        let out = self.0.clone().finalize();
+        let mut digest = RipemdDigest::default();
+        digest.bytes.copy_from_slice(&out);
+        digest
+      }
+
+      fn reset(&mut self) { self.0.reset() }
+    }
+  };
+}
+
+derive_ripemd_hasher!(Ripemd160, ripemd::Ripemd160, 20);
+derive_ripemd_hasher!(Ripemd320, ripemd::Ripemd320, 40);
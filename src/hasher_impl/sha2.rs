@@ -0,0 +1,50 @@
+use sha2::Digest as _;
+
+use crate::hasher::{Digest, Hasher};
+
+/// Multihash digest for the SHA-2 family.
+#[derive(Clone)]
+pub struct Sha2Digest<const S: usize> {
+  bytes: [u8; S],
+}
+
+impl<const S: usize> Default for Sha2Digest<S> {
+  fn default() -> Self { Self { bytes: [0; S] } }
+}
+
+impl<const S: usize> AsRef<[u8]> for Sha2Digest<S> {
+  fn as_ref(&self) -> &[u8] { &self.bytes }
+}
+
+impl<const S: usize> AsMut<[u8]> for Sha2Digest<S> {
+  fn as_mut(&mut self) -> &mut [u8] { &mut self.bytes }
+}
+
+impl<const S: usize> Digest<S> for Sha2Digest<S> {}
+
+macro_rules! derive_sha2_hasher {
+  ($name:ident, $inner:ty, $size:expr) => {
+    #[derive(Debug, Default)]
+    pub struct $name($inner);
+
+    impl Hasher for $name {
+      type Digest = Sha2Digest<$size>;
+
+      fn update(&mut self, input: &[u8]) { self.0.update(input) }
+
+      fn finalize(&self) -> Self::Digest {
+        let out = self.0.clone().finalize();
+        let mut digest = Sha2Digest::default();
+        digest.bytes.copy_from_slice(&out);
+        digest
+      }
+
+      fn reset(&mut self) { self.0.reset() }
+    }
+  };
+}
+
+derive_sha2_hasher!(Sha2_256, sha2::Sha256, 32);
+derive_sha2_hasher!(Sha2_512, sha2::Sha512, 64);
+derive_sha2_hasher!(Sha512_224, sha2::Sha512_224, 28);
+derive_sha2_hasher!(Sha512_256, sha2::Sha512_256, 32);
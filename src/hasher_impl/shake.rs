@@ -0,0 +1,57 @@
+use sha3::digest::{ExtendableOutput, Update, XofReader};
+
+use crate::hasher::{Digest, Hasher};
+
+/// SHAKE digest of caller-chosen length `S`.
+///
+/// Unlike the fixed-output hashers in this crate, a SHAKE digest's size is
+/// not implied by the algorithm, only by how many bytes were squeezed out of
+/// it. `S` is therefore picked by the caller (e.g. via [`Shake128Hasher<64>`]
+/// for a 64-byte squeeze) rather than by the multihash code.
+#[derive(Clone)]
+pub struct ShakeDigest<const S: usize> {
+  bytes: [u8; S],
+}
+
+impl<const S: usize> Default for ShakeDigest<S> {
+  fn default() -> Self { Self { bytes: [0; S] } }
+}
+
+impl<const S: usize> AsRef<[u8]> for ShakeDigest<S> {
+  fn as_ref(&self) -> &[u8] { &self.bytes }
+}
+
+impl<const S: usize> AsMut<[u8]> for ShakeDigest<S> {
+  fn as_mut(&mut self) -> &mut [u8] { &mut self.bytes }
+}
+
+impl<const S: usize> Digest<S> for ShakeDigest<S> {}
+
+macro_rules! derive_shake_hasher {
+  ($name:ident, $xof:ty, $size:expr) => {
+    /// SHAKE hasher squeezing a fixed
+    #[doc = concat!(stringify!($size), "-byte")]
+    /// digest. Use a different const parameter to squeeze a different
+    /// number of bytes from the same sponge.
+    #[derive(Default)]
+    pub struct $name<const S: usize = $size>($xof);
+
+    impl<const S: usize> Hasher for $name<S> {
+      type Digest = ShakeDigest<S>;
+
+      fn update(&mut self, input: &[u8]) { Update::update(&mut self.0, input) }
+
+      fn finalize(&self) -> Self::Digest {
+        let mut reader = self.0.clone().finalize_xof();
+        let mut digest = ShakeDigest::default();
+        reader.read(&mut digest.bytes);
+        digest
+      }
+
+      fn reset(&mut self) { self.0 = <$xof>::default() }
+    }
+  };
+}
+
+derive_shake_hasher!(Shake128Hasher, sha3::Shake128, 64);
+derive_shake_hasher!(Shake256Hasher, sha3::Shake256, 64);
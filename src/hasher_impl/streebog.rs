@@ -0,0 +1,48 @@
+use streebog::Digest as _;
+
+use crate::hasher::{Digest, Hasher};
+
+/// Multihash digest for GOST R 34.11-2012 (Streebog).
+#[derive(Clone)]
+pub struct StreebogDigest<const S: usize> {
+  bytes: [u8; S],
+}
+
+impl<const S: usize> Default for StreebogDigest<S> {
+  fn default() -> Self { Self { bytes: [0; S] } }
+}
+
+impl<const S: usize> AsRef<[u8]> for StreebogDigest<S> {
+  fn as_ref(&self) -> &[u8] { &self.bytes }
+}
+
+impl<const S: usize> AsMut<[u8]> for StreebogDigest<S> {
+  fn as_mut(&mut self) -> &mut [u8] { &mut self.bytes }
+}
+
+impl<const S: usize> Digest<S> for StreebogDigest<S> {}
+
+macro_rules! derive_streebog_hasher {
+  ($name:ident, $inner:ty, $size:expr) => {
+    #[derive(Debug, Default)]
+    pub struct $name($inner);
+
+    impl Hasher for $name {
+      type Digest = StreebogDigest<$size>;
+
+      fn update(&mut self, input: &[u8]) { self.0.update(input) }
+
+      fn finalize(&self) -> Self::Digest {
+        let out = self.0.clone().finalize();
+        let mut digest = StreebogDigest::default();
+        digest.bytes.copy_from_slice(&out);
+        digest
+      }
+
+      fn reset(&mut self) { self.0.reset() }
+    }
+  };
+}
+
+derive_streebog_hasher!(Streebog256, streebog::Streebog256, 32);
+derive_streebog_hasher!(Streebog512, streebog::Streebog512, 64);
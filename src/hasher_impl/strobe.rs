@@ -0,0 +1,67 @@
+use strobe_rs::{SecParam, Strobe};
+
+use crate::hasher::{Digest, Hasher};
+
+/// Multihash digest produced by a STROBE `PRF` squeeze.
+#[derive(Clone)]
+pub struct StrobeDigest<const S: usize> {
+  bytes: [u8; S],
+}
+
+impl<const S: usize> Default for StrobeDigest<S> {
+  fn default() -> Self { Self { bytes: [0; S] } }
+}
+
+impl<const S: usize> AsRef<[u8]> for StrobeDigest<S> {
+  fn as_ref(&self) -> &[u8] { &self.bytes }
+}
+
+impl<const S: usize> AsMut<[u8]> for StrobeDigest<S> {
+  fn as_mut(&mut self) -> &mut [u8] { &mut self.bytes }
+}
+
+impl<const S: usize> Digest<S> for StrobeDigest<S> {}
+
+macro_rules! derive_strobe_hasher {
+  ($name:ident, $sec:expr, $size:expr) => {
+    /// STROBE hasher: absorbs input via repeated `AD` operations into a
+    /// Keccak-f[1600] duplex, then squeezes the digest via `PRF`.
+    pub struct $name {
+      strobe: Strobe,
+      // STROBE's `ad(_, more)` continues the previous `AD` operation when
+      // `more` is set, so only the very first call may pass `false`.
+      started: bool,
+    }
+
+    impl core::fmt::Debug for $name {
+      fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct(stringify!($name)).field("started", &self.started).finish()
+      }
+    }
+
+    impl Default for $name {
+      fn default() -> Self { Self { strobe: Strobe::new(b"multihash", $sec), started: false } }
+    }
+
+    impl Hasher for $name {
+      type Digest = StrobeDigest<$size>;
+
+      fn update(&mut self, input: &[u8]) {
+        self.strobe.ad(input, self.started);
+        self.started = true;
+      }
+
+      fn finalize(&self) -> Self::Digest {
+        let mut strobe = self.strobe.clone();
+        let mut digest = StrobeDigest::default();
+        strobe.prf(&mut digest.bytes, false);
+        digest
+      }
+
+      fn reset(&mut self) { *self = Self::default() }
+    }
+  };
+}
+
+derive_strobe_hasher!(Strobe256, SecParam::B128, 32);
+derive_strobe_hasher!(Strobe512, SecParam::B256, 64);
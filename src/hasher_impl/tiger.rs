@@ -0,0 +1,41 @@
+use tiger::Digest as _;
+
+use crate::hasher::{Digest, Hasher};
+
+/// Multihash digest for Tiger (24-byte hash size).
+#[derive(Clone)]
+pub struct TigerDigest<const S: usize> {
+  bytes: [u8; S],
+}
+
+impl<const S: usize> Default for TigerDigest<S> {
+  fn default() -> Self { Self { bytes: [0; S] } }
+}
+
+impl<const S: usize> AsRef<[u8]> for TigerDigest<S> {
+  fn as_ref(&self) -> &[u8] { &self.bytes }
+}
+
+impl<const S: usize> AsMut<[u8]> for TigerDigest<S> {
+  fn as_mut(&mut self) -> &mut [u8] { &mut self.bytes }
+}
+
+impl<const S: usize> Digest<S> for TigerDigest<S> {}
+
+#[derive(Debug, Default)]
+pub struct Tiger(tiger::Tiger);
+
+impl Hasher for Tiger {
+  type Digest = TigerDigest<24>;
+
+  fn update(&mut self, input: &[u8]) { self.0.update(input) }
+
+  fn finalize(&self) -> Self::Digest {
+    let out = self.0.clone().finalize();
+    let mut digest = TigerDigest::default();
+    digest.bytes.copy_from_slice(&out);
+    digest
+  }
+
+  fn reset(&mut self) { self.0.reset() }
+}
@@ -0,0 +1,74 @@
+//! Incremental encode/decode of multihashes against an `io::Read`/`io::Write`,
+//! so large inputs can be hashed without buffering them up front.
+//!
+//! Mirrors the reference `multihash` crate's `read_code`/`read_digest`/
+//! `write_mh` trio. The `io` traits themselves come from `std::io` when the
+//! `std` feature is enabled, and from `core2::io` otherwise so this still
+//! works on `no_std` targets with an allocator.
+
+#[cfg(feature = "std")]
+use std::io;
+
+#[cfg(not(feature = "std"))]
+use core2::io;
+
+use unsigned_varint::io::read_u64;
+
+use crate::{
+  hasher::{Digest, Hasher},
+  multihash_impl::Code,
+};
+
+/// Reads the unsigned-varint multihash code off `r`.
+pub fn read_code<R: io::Read>(r: &mut R) -> io::Result<u64> {
+  read_u64(r).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+}
+
+/// Reads a varint-prefixed digest of `r` into `D`, where the varint gives the
+/// digest length in bytes.
+pub fn read_digest<R: io::Read, D: Digest<S>, const S: usize>(r: &mut R) -> io::Result<D> {
+  let len = read_u64(r).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))? as usize;
+  if len != S {
+    return Err(io::Error::new(
+      io::ErrorKind::InvalidData,
+      "digest length does not match the requested digest type",
+    ));
+  }
+  let mut digest = D::default();
+  r.read_exact(digest.as_mut())?;
+  Ok(digest)
+}
+
+/// Writes `varint(code) || varint(digest.len()) || digest` to `w`.
+pub fn write_mh<W: io::Write>(w: &mut W, code: u64, digest: &[u8]) -> io::Result<()> {
+  let mut buf = unsigned_varint::encode::u64_buffer();
+  w.write_all(unsigned_varint::encode::u64(code, &mut buf))?;
+  let mut buf = unsigned_varint::encode::u64_buffer();
+  w.write_all(unsigned_varint::encode::u64(digest.len() as u64, &mut buf))?;
+  w.write_all(digest)
+}
+
+/// An `io::Write` adapter that feeds every byte written to it into a hasher
+/// `H`, such as one from the [`Code`] table. [`WriteHasher::finalize`]
+/// consumes the adapter and returns the hasher's digest, not a multihash;
+/// `Code::multihash_from_digest` wraps that digest into one.
+pub struct WriteHasher<H> {
+  hasher: H,
+}
+
+impl<H: Hasher + Default> WriteHasher<H> {
+  /// Creates a `WriteHasher` around a fresh instance of `H`.
+  pub fn new() -> Self { Self { hasher: H::default() } }
+
+  /// Finalizes the wrapped hasher into its digest, consuming the adapter.
+  pub fn finalize(self) -> H::Digest { self.hasher.finalize() }
+}
+
+impl<H: Hasher> io::Write for WriteHasher<H> {
+  fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+    self.hasher.update(buf);
+    Ok(buf.len())
+  }
+
+  fn flush(&mut self) -> io::Result<()> { Ok(()) }
+}
@@ -18,6 +18,14 @@ pub enum Code {
   #[cfg(feature = "sha2")]
   #[mh(code = 0x13, hasher = crate::Sha2_512, digest = crate::Sha2Digest<64>)]
   Sha2_512,
+  /// SHA-512/224 (28-byte hash size)
+  #[cfg(feature = "sha2")]
+  #[mh(code = 0x1005, hasher = crate::Sha512_224, digest = crate::Sha2Digest<28>)]
+  Sha512_224,
+  /// SHA-512/256 (32-byte hash size)
+  #[cfg(feature = "sha2")]
+  #[mh(code = 0x1006, hasher = crate::Sha512_256, digest = crate::Sha2Digest<32>)]
+  Sha512_256,
   /// SHA3-224 (28-byte hash size)
   #[cfg(feature = "sha3")]
   #[mh(code = 0x17, hasher = crate::Sha3_224, digest = crate::Sha3Digest<28>)]
@@ -79,6 +87,31 @@ pub enum Code {
   #[mh(code = 0x1e, hasher = crate::Blake3_256, digest =
  crate::Blake3Digest<32>)]
   Blake3_256,
+  /// SHAKE-128 (default 64-byte squeeze; use `Shake128Hasher::<N>` directly
+  /// for a different output length)
+  #[cfg(feature = "sha3")]
+  #[mh(code = 0x18, hasher = crate::Shake128Hasher::<64>, digest =
+ crate::ShakeDigest<64>)]
+  Shake128,
+  /// SHAKE-256 (default 64-byte squeeze; use `Shake256Hasher::<N>` directly
+  /// for a different output length)
+  #[cfg(feature = "sha3")]
+  #[mh(code = 0x19, hasher = crate::Shake256Hasher::<64>, digest =
+ crate::ShakeDigest<64>)]
+  Shake256,
+  /// KangarooTwelve (32-byte hash size)
+  #[cfg(feature = "k12")]
+  #[mh(code = 0x1f, hasher = crate::K12_256, digest = crate::K12Digest<32>)]
+  K12_256,
+
+  /// STROBE-256 (32-byte hash size)
+  #[cfg(feature = "strobe")]
+  #[mh(code = 0x3312e6, hasher = crate::Strobe256, digest = crate::StrobeDigest<32>)]
+  Strobe256,
+  /// STROBE-512 (64-byte hash size)
+  #[cfg(feature = "strobe")]
+  #[mh(code = 0x3312e7, hasher = crate::Strobe512, digest = crate::StrobeDigest<64>)]
+  Strobe512,
 
   // The following hashes are not cryptographically secure hashes and are not
   // enabled by default
@@ -87,6 +120,47 @@ pub enum Code {
   #[mh(code = 0x00, hasher = crate::IdentityHasher::<64>, digest =
  crate::IdentityDigest<64>)]
   Identity,
+
+  // The following are legacy/interop hash functions, off by default to keep
+  // the cryptographically secure table and `no_std` builds minimal. Unlike
+  // `Identity` above, they aren't broken or insecure by design.
+  /// GOST R 34.11-2012 Streebog-256 (32-byte hash size)
+  #[cfg(feature = "streebog")]
+  #[mh(code = 0x1012, hasher = crate::Streebog256, digest =
+ crate::StreebogDigest<32>)]
+  Streebog256,
+  /// GOST R 34.11-2012 Streebog-512 (64-byte hash size)
+  #[cfg(feature = "streebog")]
+  #[mh(code = 0x1013, hasher = crate::Streebog512, digest =
+ crate::StreebogDigest<64>)]
+  Streebog512,
+  /// RIPEMD-160 (20-byte hash size)
+  #[cfg(feature = "ripemd")]
+  #[mh(code = 0x1053, hasher = crate::Ripemd160, digest = crate::RipemdDigest<20>)]
+  Ripemd160,
+  /// RIPEMD-320 (40-byte hash size)
+  #[cfg(feature = "ripemd")]
+  #[mh(code = 0x1055, hasher = crate::Ripemd320, digest = crate::RipemdDigest<40>)]
+  Ripemd320,
+  /// Grøstl-256 (32-byte hash size)
+  #[cfg(feature = "groestl")]
+  #[mh(code = 0x1020, hasher = crate::Groestl256, digest =
+ crate::GroestlDigest<32>)]
+  Groestl256,
+  /// Grøstl-512 (64-byte hash size)
+  #[cfg(feature = "groestl")]
+  #[mh(code = 0x1021, hasher = crate::Groestl512, digest =
+ crate::GroestlDigest<64>)]
+  Groestl512,
+  /// Tiger (24-byte hash size)
+  #[cfg(feature = "tiger")]
+  #[mh(code = 0x1030, hasher = crate::Tiger, digest = crate::TigerDigest<24>)]
+  Tiger,
+  /// Whirlpool (64-byte hash size)
+  #[cfg(feature = "whirlpool")]
+  #[mh(code = 0x1040, hasher = crate::Whirlpool, digest =
+ crate::WhirlpoolDigest<64>)]
+  Whirlpool,
 }
 
 #[cfg(test)]
@@ -94,9 +168,38 @@ mod tests {
   use super::*;
   use crate::{
     hasher::Hasher,
-    hasher_impl::sha3::{
-      Sha3_256,
-      Sha3_512,
+    hasher_impl::{
+      sha3::{
+        Sha3_256,
+        Sha3_512,
+      },
+      groestl::{
+        Groestl256,
+        Groestl512,
+      },
+      k12::K12_256,
+      ripemd::{
+        Ripemd160,
+        Ripemd320,
+      },
+      sha2::{
+        Sha512_224,
+        Sha512_256,
+      },
+      shake::{
+        Shake128Hasher,
+        Shake256Hasher,
+      },
+      streebog::{
+        Streebog256,
+        Streebog512,
+      },
+      strobe::{
+        Strobe256,
+        Strobe512,
+      },
+      tiger::Tiger,
+      whirlpool::Whirlpool,
     },
     multihash::MultihashDigest,
   };
@@ -122,4 +225,322 @@ mod tests {
     assert_eq!(hash.digest(), digest.as_ref());
     assert_eq!(hash, hash2);
   }
+
+  #[test]
+  fn test_hasher_shake128() {
+    let digest = Shake128Hasher::<64>::digest(b"hello world");
+    let hash = Code::multihash_from_digest(&digest);
+    let hash2 = Code::Shake128.digest(b"hello world");
+    assert_eq!(hash.code(), u64::from(Code::Shake128));
+    assert_eq!(hash.size(), 64);
+    assert_eq!(hash.digest(), digest.as_ref());
+    assert_eq!(hash, hash2);
+  }
+
+  #[test]
+  fn test_hasher_shake256() {
+    let digest = Shake256Hasher::<64>::digest(b"hello world");
+    let hash = Code::multihash_from_digest(&digest);
+    let hash2 = Code::Shake256.digest(b"hello world");
+    assert_eq!(hash.code(), u64::from(Code::Shake256));
+    assert_eq!(hash.size(), 64);
+    assert_eq!(hash.digest(), digest.as_ref());
+    assert_eq!(hash, hash2);
+  }
+
+  // First 32 output bytes of SHAKE128/SHAKE256 squeezed from the ASCII
+  // message "abc", per NIST FIPS 202.
+  #[test]
+  fn test_hasher_shake128_known_answer() {
+    const EXPECTED: [u8; 32] = [
+      0x58, 0x81, 0x09, 0x2d, 0xd8, 0x18, 0xbf, 0x5c, 0xf8, 0xa3, 0xdd, 0xb7, 0x93, 0xfb, 0xcb,
+      0xa7, 0x40, 0x97, 0xd5, 0xc5, 0x26, 0xa6, 0xd3, 0x5f, 0x97, 0xb8, 0x33, 0x51, 0x94, 0x0f,
+      0x2c, 0xc8,
+    ];
+    assert_eq!(Shake128Hasher::<32>::digest(b"abc").as_ref(), &EXPECTED[..]);
+  }
+
+  #[test]
+  fn test_hasher_shake256_known_answer() {
+    const EXPECTED: [u8; 32] = [
+      0x48, 0x33, 0x66, 0x60, 0x13, 0x60, 0xa8, 0x77, 0x1c, 0x68, 0x63, 0x08, 0x0c, 0xc4, 0x11,
+      0x4d, 0x8d, 0xb4, 0x45, 0x30, 0xf8, 0xf1, 0xe1, 0xee, 0x4f, 0x94, 0xea, 0x37, 0xe7, 0x8b,
+      0x57, 0x39,
+    ];
+    assert_eq!(Shake256Hasher::<32>::digest(b"abc").as_ref(), &EXPECTED[..]);
+  }
+
+  #[test]
+  fn test_hasher_k12_256() {
+    let digest = K12_256::digest(b"hello world");
+    let hash = Code::multihash_from_digest(&digest);
+    let hash2 = Code::K12_256.digest(b"hello world");
+    assert_eq!(hash.code(), u64::from(Code::K12_256));
+    assert_eq!(hash.size(), 32);
+    assert_eq!(hash.digest(), digest.as_ref());
+    assert_eq!(hash, hash2);
+  }
+
+  // KangarooTwelve test vector for an empty message with an empty
+  // customization string (draft-irtf-cfrg-kangarootwelve, 32-byte output).
+  #[test]
+  fn test_hasher_k12_256_known_answer() {
+    const EXPECTED: [u8; 32] = [
+      0x1a, 0xc2, 0xd4, 0x50, 0xfc, 0x3b, 0x42, 0x05, 0xd1, 0x9d, 0xa7, 0xbf, 0xca, 0x1b, 0x37,
+      0x51, 0x3c, 0x08, 0x03, 0x57, 0x7a, 0xc7, 0x16, 0x7f, 0x06, 0xfe, 0x2c, 0xe1, 0xf0, 0xef,
+      0x39, 0xe5,
+    ];
+    assert_eq!(K12_256::digest(b"").as_ref(), &EXPECTED[..]);
+  }
+
+  #[test]
+  fn test_hasher_k12_256_update_is_chunk_independent() {
+    // K12's tree mode splits its input into 8192-byte chunks internally;
+    // exercise that boundary the same way the STROBE chunk-independence test
+    // does, by comparing one `update()` call against several.
+    let long_input = vec![0x61u8; 8192 * 2 + 100];
+    let whole = K12_256::digest(&long_input);
+    let mut chunked = K12_256::default();
+    for chunk in long_input.chunks(4096) {
+      chunked.update(chunk);
+    }
+    assert_eq!(chunked.finalize().as_ref(), whole.as_ref());
+  }
+
+  #[test]
+  fn test_hasher_sha512_224() {
+    let digest = Sha512_224::digest(b"hello world");
+    let hash = Code::multihash_from_digest(&digest);
+    let hash2 = Code::Sha512_224.digest(b"hello world");
+    assert_eq!(hash.code(), u64::from(Code::Sha512_224));
+    assert_eq!(hash.size(), 28);
+    assert_eq!(hash.digest(), digest.as_ref());
+    assert_eq!(hash, hash2);
+  }
+
+  #[test]
+  fn test_hasher_sha512_256() {
+    let digest = Sha512_256::digest(b"hello world");
+    let hash = Code::multihash_from_digest(&digest);
+    let hash2 = Code::Sha512_256.digest(b"hello world");
+    assert_eq!(hash.code(), u64::from(Code::Sha512_256));
+    assert_eq!(hash.size(), 32);
+    assert_eq!(hash.digest(), digest.as_ref());
+    assert_eq!(hash, hash2);
+  }
+
+  // FIPS 180-4 test vectors for the ASCII message "abc".
+  #[test]
+  fn test_hasher_sha512_224_known_answer() {
+    const EXPECTED: [u8; 28] = [
+      0x46, 0x34, 0x27, 0x0f, 0x70, 0x7b, 0x6a, 0x54, 0xda, 0xae, 0x75, 0x30, 0x46, 0x08, 0x42,
+      0xe2, 0x0e, 0x37, 0xed, 0x26, 0x5c, 0xee, 0xe9, 0xa4, 0x3e, 0x89, 0x24, 0xaa,
+    ];
+    assert_eq!(Sha512_224::digest(b"abc").as_ref(), &EXPECTED[..]);
+  }
+
+  #[test]
+  fn test_hasher_sha512_256_known_answer() {
+    const EXPECTED: [u8; 32] = [
+      0x53, 0x04, 0x8e, 0x26, 0x81, 0x94, 0x1e, 0xf9, 0x9b, 0x2e, 0x29, 0xb7, 0x6b, 0x4c, 0x7d,
+      0xab, 0xe4, 0xc2, 0xd0, 0xc6, 0x34, 0xfc, 0x6d, 0x46, 0xe0, 0xe2, 0xf1, 0x31, 0x07, 0xe7,
+      0xaf, 0x23,
+    ];
+    assert_eq!(Sha512_256::digest(b"abc").as_ref(), &EXPECTED[..]);
+  }
+
+  #[test]
+  fn test_hasher_strobe256() {
+    let digest = Strobe256::digest(b"hello world");
+    let hash = Code::multihash_from_digest(&digest);
+    let hash2 = Code::Strobe256.digest(b"hello world");
+    assert_eq!(hash.code(), u64::from(Code::Strobe256));
+    assert_eq!(hash.size(), 32);
+    assert_eq!(hash.digest(), digest.as_ref());
+    assert_eq!(hash, hash2);
+  }
+
+  #[test]
+  fn test_hasher_strobe512() {
+    let digest = Strobe512::digest(b"hello world");
+    let hash = Code::multihash_from_digest(&digest);
+    let hash2 = Code::Strobe512.digest(b"hello world");
+    assert_eq!(hash.code(), u64::from(Code::Strobe512));
+    assert_eq!(hash.size(), 64);
+    assert_eq!(hash.digest(), digest.as_ref());
+    assert_eq!(hash, hash2);
+  }
+
+  #[test]
+  fn test_hasher_strobe256_update_is_chunk_independent() {
+    let whole = Strobe256::digest(b"hello world");
+    let mut chunked = Strobe256::default();
+    chunked.update(b"hello ");
+    chunked.update(b"world");
+    assert_eq!(chunked.finalize().as_ref(), whole.as_ref());
+  }
+
+  // STROBE has no NIST-style KAT to transcribe; instead rebuild the expected
+  // output from first principles by driving `strobe_rs::Strobe` by hand the
+  // same way our wrapper's `ad`/`started`/`prf` wiring is supposed to,
+  // independently of our own `Strobe256` type. This pins the first `ad` call
+  // to `more = false`.
+  #[test]
+  fn test_hasher_strobe256_matches_hand_driven_protocol() {
+    use strobe_rs::{SecParam, Strobe};
+    let mut strobe = Strobe::new(b"multihash", SecParam::B128);
+    strobe.ad(b"hello world", false);
+    let mut expected = [0u8; 32];
+    strobe.prf(&mut expected, false);
+    assert_eq!(Strobe256::digest(b"hello world").as_ref(), &expected[..]);
+  }
+
+  #[test]
+  fn test_hasher_streebog256() {
+    let digest = Streebog256::digest(b"hello world");
+    let hash = Code::multihash_from_digest(&digest);
+    let hash2 = Code::Streebog256.digest(b"hello world");
+    assert_eq!(hash.code(), u64::from(Code::Streebog256));
+    assert_eq!(hash.size(), 32);
+    assert_eq!(hash.digest(), digest.as_ref());
+    assert_eq!(hash, hash2);
+  }
+
+  #[test]
+  fn test_hasher_streebog512() {
+    let digest = Streebog512::digest(b"hello world");
+    let hash = Code::multihash_from_digest(&digest);
+    let hash2 = Code::Streebog512.digest(b"hello world");
+    assert_eq!(hash.code(), u64::from(Code::Streebog512));
+    assert_eq!(hash.size(), 64);
+    assert_eq!(hash.digest(), digest.as_ref());
+    assert_eq!(hash, hash2);
+  }
+
+  // See the comment above `test_hasher_ripemd320_matches_reference_impl`:
+  // no hand-transcribable GOST R 34.11-2012 KAT handy, so cross-check our
+  // wrapper against the `streebog` crate's own one-shot API instead.
+  #[test]
+  fn test_hasher_streebog256_matches_reference_impl() {
+    use streebog::Digest as _;
+    let expected = streebog::Streebog256::digest(b"abc");
+    assert_eq!(Streebog256::digest(b"abc").as_ref(), expected.as_slice());
+  }
+
+  #[test]
+  fn test_hasher_ripemd160() {
+    let digest = Ripemd160::digest(b"hello world");
+    let hash = Code::multihash_from_digest(&digest);
+    let hash2 = Code::Ripemd160.digest(b"hello world");
+    assert_eq!(hash.code(), u64::from(Code::Ripemd160));
+    assert_eq!(hash.size(), 20);
+    assert_eq!(hash.digest(), digest.as_ref());
+    assert_eq!(hash, hash2);
+  }
+
+  // RIPEMD-160 test vector for the ASCII message "abc", from the original
+  // RIPEMD-160 reference test suite.
+  #[test]
+  fn test_hasher_ripemd160_known_answer() {
+    const EXPECTED: [u8; 20] = [
+      0x8e, 0xb2, 0x08, 0xf7, 0xe0, 0x5d, 0x98, 0x7a, 0x9b, 0x04, 0x4a, 0x8e, 0x98, 0xc6, 0xb0,
+      0x87, 0xf1, 0x5a, 0x0b, 0xfc,
+    ];
+    assert_eq!(Ripemd160::digest(b"abc").as_ref(), &EXPECTED[..]);
+  }
+
+  #[test]
+  fn test_hasher_ripemd320() {
+    let digest = Ripemd320::digest(b"hello world");
+    let hash = Code::multihash_from_digest(&digest);
+    let hash2 = Code::Ripemd320.digest(b"hello world");
+    assert_eq!(hash.code(), u64::from(Code::Ripemd320));
+    assert_eq!(hash.size(), 40);
+    assert_eq!(hash.digest(), digest.as_ref());
+    assert_eq!(hash, hash2);
+  }
+
+  // RIPEMD-320, Grøstl, Tiger and Streebog don't have a NIST-style KAT handy
+  // to transcribe by hand here, so these check our thin `Hasher` wrapper
+  // against the `ripemd`/`groestl`/`tiger`/`streebog` crates' own one-shot
+  // API called directly, rather than against the self-consistency loop
+  // above. That still exercises the wiring (byte order, truncation) between
+  // our wrapper and the reference implementation it wraps.
+  #[test]
+  fn test_hasher_ripemd320_matches_reference_impl() {
+    use ripemd::Digest as _;
+    let expected = ripemd::Ripemd320::digest(b"abc");
+    assert_eq!(Ripemd320::digest(b"abc").as_ref(), expected.as_slice());
+  }
+
+  #[test]
+  fn test_hasher_groestl256() {
+    let digest = Groestl256::digest(b"hello world");
+    let hash = Code::multihash_from_digest(&digest);
+    let hash2 = Code::Groestl256.digest(b"hello world");
+    assert_eq!(hash.code(), u64::from(Code::Groestl256));
+    assert_eq!(hash.size(), 32);
+    assert_eq!(hash.digest(), digest.as_ref());
+    assert_eq!(hash, hash2);
+  }
+
+  #[test]
+  fn test_hasher_groestl512() {
+    let digest = Groestl512::digest(b"hello world");
+    let hash = Code::multihash_from_digest(&digest);
+    let hash2 = Code::Groestl512.digest(b"hello world");
+    assert_eq!(hash.code(), u64::from(Code::Groestl512));
+    assert_eq!(hash.size(), 64);
+    assert_eq!(hash.digest(), digest.as_ref());
+    assert_eq!(hash, hash2);
+  }
+
+  #[test]
+  fn test_hasher_groestl256_matches_reference_impl() {
+    use groestl::Digest as _;
+    let expected = groestl::Groestl256::digest(b"abc");
+    assert_eq!(Groestl256::digest(b"abc").as_ref(), expected.as_slice());
+  }
+
+  #[test]
+  fn test_hasher_tiger() {
+    let digest = Tiger::digest(b"hello world");
+    let hash = Code::multihash_from_digest(&digest);
+    let hash2 = Code::Tiger.digest(b"hello world");
+    assert_eq!(hash.code(), u64::from(Code::Tiger));
+    assert_eq!(hash.size(), 24);
+    assert_eq!(hash.digest(), digest.as_ref());
+    assert_eq!(hash, hash2);
+  }
+
+  #[test]
+  fn test_hasher_tiger_matches_reference_impl() {
+    use tiger::Digest as _;
+    let expected = tiger::Tiger::digest(b"abc");
+    assert_eq!(Tiger::digest(b"abc").as_ref(), expected.as_slice());
+  }
+
+  #[test]
+  fn test_hasher_whirlpool() {
+    let digest = Whirlpool::digest(b"hello world");
+    let hash = Code::multihash_from_digest(&digest);
+    let hash2 = Code::Whirlpool.digest(b"hello world");
+    assert_eq!(hash.code(), u64::from(Code::Whirlpool));
+    assert_eq!(hash.size(), 64);
+    assert_eq!(hash.digest(), digest.as_ref());
+    assert_eq!(hash, hash2);
+  }
+
+  // ISO/IEC 10118-3 Whirlpool test vector for the ASCII message "abc".
+  #[test]
+  fn test_hasher_whirlpool_known_answer() {
+    const EXPECTED: [u8; 64] = [
+      0x4e, 0x24, 0x48, 0xa4, 0xc6, 0xf4, 0x86, 0xbb, 0x16, 0xb6, 0x56, 0x2c, 0x73, 0xb4, 0x02,
+      0x0b, 0xf3, 0x04, 0x3e, 0x3a, 0x73, 0x1b, 0xce, 0x72, 0x1a, 0xe1, 0xb3, 0x03, 0xd9, 0x7e,
+      0x6d, 0x4c, 0x71, 0x81, 0xee, 0xbd, 0xb6, 0xc5, 0x7e, 0x27, 0x7d, 0x0e, 0x34, 0x95, 0x71,
+      0x14, 0xcb, 0xd6, 0xc7, 0x97, 0xfc, 0x9d, 0x95, 0xd8, 0xb5, 0x82, 0xd2, 0x25, 0x29, 0x20,
+      0x76, 0xd4, 0xee, 0xf5,
+    ];
+    assert_eq!(Whirlpool::digest(b"abc").as_ref(), &EXPECTED[..]);
+  }
 }
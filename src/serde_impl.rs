@@ -0,0 +1,133 @@
+//! `serde` support for the generated [`Code`](crate::multihash_impl::Code)
+//! table and the [`Multihash`](crate::multihash::Multihash) digest type,
+//! gated behind the `serde-codec` feature.
+//!
+//! `Code` round-trips through its bare `u64` code. `Multihash` round-trips
+//! through the canonical `code`-varint-prefixed byte form (`varint(code) ||
+//! varint(len) || digest`), the same form every other multihash
+//! implementation produces, streamed out one byte at a time via
+//! `serialize_seq` so no buffer has to be sized up front: the two varints
+//! are runtime-length-dependent and the digest is only known to fit in
+//! `alloc_size` at the *table* level, not for an arbitrary `Multihash<S>`
+//! built from a caller-chosen XOF length, so a single fixed-size stack
+//! buffer can't safely hold all three. Deserializing reads the two varints
+//! into a 20-byte buffer (at most 10 bytes each) and the digest into a
+//! `[u8; S]` buffer, of which only the first `len` bytes are used.
+
+#[cfg(feature = "serde-codec")]
+mod imp {
+  use core::convert::TryFrom;
+
+  use serde::{
+    de::{Error as DeError, SeqAccess, Visitor},
+    ser::SerializeSeq,
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+  };
+
+  use crate::multihash::Multihash;
+  use crate::multihash_impl::Code;
+
+  impl Serialize for Code {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+      serializer.serialize_u64(u64::from(*self))
+    }
+  }
+
+  impl<'de> Deserialize<'de> for Code {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+      struct CodeVisitor;
+
+      impl<'de> Visitor<'de> for CodeVisitor {
+        type Value = Code;
+
+        fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+          f.write_str("a multihash code")
+        }
+
+        fn visit_u64<E: DeError>(self, value: u64) -> Result<Code, E> {
+          Code::try_from(value)
+            .map_err(|_| E::custom(format_args!("unsupported multihash code {}", value)))
+        }
+      }
+
+      deserializer.deserialize_u64(CodeVisitor)
+    }
+  }
+
+  impl<const S: usize> Serialize for Multihash<S> {
+    fn serialize<Ser: Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+      let mut code_buf = unsigned_varint::encode::u64_buffer();
+      let code_bytes = unsigned_varint::encode::u64(self.code(), &mut code_buf);
+      let mut len_buf = unsigned_varint::encode::u64_buffer();
+      let len_bytes = unsigned_varint::encode::u64(self.size() as u64, &mut len_buf);
+      let digest = self.digest();
+
+      let mut seq =
+        serializer.serialize_seq(Some(code_bytes.len() + len_bytes.len() + digest.len()))?;
+      for byte in code_bytes.iter().chain(len_bytes).chain(digest) {
+        seq.serialize_element(byte)?;
+      }
+      seq.end()
+    }
+  }
+
+  impl<'de, const S: usize> Deserialize<'de> for Multihash<S> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+      struct MultihashVisitor<const S: usize>;
+
+      impl<'de, const S: usize> Visitor<'de> for MultihashVisitor<S> {
+        type Value = Multihash<S>;
+
+        fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+          f.write_str("a code-varint-prefixed multihash byte sequence")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Multihash<S>, A::Error> {
+          // Every unsigned-varint byte but the last has its continuation bit
+          // (0x80) set, so each of the two varints can be pulled off one
+          // byte at a time without knowing its length up front.
+          let mut prefix = [0u8; 20];
+          let mut prefix_len = 0;
+          let mut varints_seen = 0;
+          while varints_seen < 2 {
+            if prefix_len == prefix.len() {
+              return Err(DeError::custom("invalid multihash prefix varint"));
+            }
+            let byte: u8 = seq
+              .next_element()?
+              .ok_or_else(|| DeError::custom("truncated multihash prefix"))?;
+            prefix[prefix_len] = byte;
+            prefix_len += 1;
+            if byte & 0x80 == 0 {
+              varints_seen += 1;
+            }
+          }
+
+          let (code, rest) = unsigned_varint::decode::u64(&prefix[..prefix_len])
+            .map_err(|_| DeError::custom("invalid multihash code varint"))?;
+          let (len, _) = unsigned_varint::decode::u64(rest)
+            .map_err(|_| DeError::custom("invalid multihash length varint"))?;
+          let len = len as usize;
+          if len > S {
+            return Err(DeError::custom("multihash digest is larger than its allocated size"));
+          }
+
+          let mut digest = [0u8; S];
+          for slot in digest[..len].iter_mut() {
+            *slot = seq
+              .next_element()?
+              .ok_or_else(|| DeError::custom("truncated multihash digest"))?;
+          }
+
+          Multihash::wrap(code, &digest[..len])
+            .map_err(|_| DeError::custom("invalid multihash digest"))
+        }
+      }
+
+      deserializer.deserialize_seq(MultihashVisitor)
+    }
+  }
+}